@@ -0,0 +1,15 @@
+/// Access to the system clipboard, handed to widgets while processing an
+/// [`Event`].
+///
+/// [`Event`]: enum.Event.html
+pub trait Clipboard {
+    /// Returns the current content of the [`Clipboard`] as text, if any.
+    ///
+    /// [`Clipboard`]: trait.Clipboard.html
+    fn content(&self) -> Option<String>;
+
+    /// Sets the content of the [`Clipboard`] to the given text.
+    ///
+    /// [`Clipboard`]: trait.Clipboard.html
+    fn set_content(&mut self, content: String);
+}
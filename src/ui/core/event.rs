@@ -0,0 +1,38 @@
+//! Track whether an [`Event`] has been consumed by a widget.
+//!
+//! [`Event`]: ../enum.Event.html
+
+/// The status of an [`Event`] after being processed by a [`Widget`].
+///
+/// [`Event`]: ../enum.Event.html
+/// [`Widget`]: ../trait.Widget.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Event`] was captured. It should not be propagated to other
+    /// widgets.
+    ///
+    /// [`Event`]: ../enum.Event.html
+    Captured,
+
+    /// The [`Event`] was ignored. It may still be processed by other
+    /// widgets.
+    ///
+    /// [`Event`]: ../enum.Event.html
+    Ignored,
+}
+
+impl Status {
+    /// Merges two [`Status`] values, preferring [`Status::Captured`].
+    ///
+    /// This is useful to keep track of the status of a chain of widgets
+    /// without losing a previous capture.
+    ///
+    /// [`Status`]: enum.Status.html
+    /// [`Status::Captured`]: enum.Status.html#variant.Captured
+    pub fn merge(self, other: Self) -> Self {
+        match self {
+            Status::Captured => Status::Captured,
+            Status::Ignored => other,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::graphics::Point;
-use crate::ui::core::{Event, Hasher, Layout, MouseCursor, Node};
+use crate::ui::core::{
+    event, Clipboard, Element, Event, Hasher, Layout, MouseCursor, Node,
+};
 
 /// A component that displays information or allows interaction.
 ///
@@ -55,20 +57,74 @@ pub trait Widget<Message, Renderer>: std::fmt::Debug {
     ///   * an [`Event`] describing user interaction
     ///   * the computed [`Layout`] of the [`Widget`]
     ///   * the current cursor position
+    ///   * the `Renderer`, in case the [`Widget`] needs to measure content
+    ///   to decide how to react to the [`Event`]
+    ///   * a mutable [`Clipboard`], allowing the [`Widget`] to read from and
+    ///   write to the system clipboard
     ///   * a mutable `Message` vector, allowing the [`Widget`] to produce
     ///   new messages based on user interaction.
     ///
-    /// By default, it does nothing.
+    /// It must return an [`event::Status`] indicating whether the [`Event`]
+    /// was captured. Once an [`Event`] is captured, it will not be offered to
+    /// the remaining widgets in the tree (e.g. the siblings of a widget that
+    /// just captured a click).
+    ///
+    /// By default, it does nothing and returns [`event::Status::Ignored`].
     ///
     /// [`Event`]: enum.Event.html
     /// [`Widget`]: trait.Widget.html
     /// [`Layout`]: struct.Layout.html
+    /// [`Clipboard`]: trait.Clipboard.html
+    /// [`event::Status`]: event/enum.Status.html
+    /// [`event::Status::Ignored`]: event/enum.Status.html#variant.Ignored
     fn on_event(
         &mut self,
         _event: Event,
         _layout: Layout<'_>,
         _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
         _messages: &mut Vec<Message>,
-    ) {
+    ) -> event::Status {
+        event::Status::Ignored
+    }
+
+    /// Returns whether the [`Widget`] can be focused via the keyboard (e.g.
+    /// tabbed to) or not.
+    ///
+    /// By default, a [`Widget`] is not focusable.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    fn focusable(&self) -> bool {
+        false
+    }
+
+    /// Returns the overlay of the [`Widget`], if there is any.
+    ///
+    /// An overlay is just a regular [`Element`] (e.g. a dropdown menu's list
+    /// of options) that the runtime lays out and draws on top of the rest of
+    /// the user interface, after the normal widget tree, so it is never
+    /// clipped to its parent's bounds.
+    ///
+    /// The `layout` is the already-computed [`Layout`] of the [`Widget`],
+    /// which an overlay uses to decide where it should be anchored (e.g. a
+    /// dropdown menu positions its list directly below its own bounds).
+    ///
+    /// Container widgets such as [`Column`] and [`Row`] forward this call to
+    /// their children, so a [`Widget`] nested several levels deep can still
+    /// surface an overlay to the runtime.
+    ///
+    /// By default, a [`Widget`] has no overlay.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Element`]: struct.Element.html
+    /// [`Layout`]: struct.Layout.html
+    /// [`Column`]: ../widget/struct.Column.html
+    /// [`Row`]: ../widget/struct.Row.html
+    fn overlay(
+        &mut self,
+        _layout: Layout<'_>,
+    ) -> Option<Element<'_, Message, Renderer>> {
+        None
     }
 }
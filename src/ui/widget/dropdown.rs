@@ -0,0 +1,380 @@
+//! Pick from a list of choices with a `DropDownList`.
+use crate::graphics::{
+    Color, HorizontalAlignment, Point, Rectangle, VerticalAlignment,
+};
+use crate::input::{mouse, ButtonState};
+use crate::ui::core::{
+    event, Align, Clipboard, Element, Event, Hasher, Layout, MouseCursor,
+    Node, Widget,
+};
+use crate::ui::widget::{text, Column, Row, Text};
+
+use std::hash::Hash;
+
+/// A button that shows the currently selected option and expands into a
+/// list of choices when clicked.
+///
+/// The expanded list is not scrollable: every option is laid out, one below
+/// the other.
+///
+/// It implements [`Widget`] when the [`core::Renderer`] implements the
+/// [`dropdown::Renderer`] trait.
+///
+/// Unlike [`Radio`], which lays out one button per option, a
+/// [`DropDownList`] stays a single line tall no matter how many options it
+/// holds, expanding its list of choices as an overlay that floats above the
+/// rest of the user interface.
+///
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`dropdown::Renderer`]: trait.Renderer.html
+/// [`Radio`]: ../radio/struct.Radio.html
+/// [`DropDownList`]: struct.DropDownList.html
+pub struct DropDownList<Message> {
+    options: Vec<String>,
+    selected: usize,
+    on_selected: Box<dyn Fn(usize) -> Message>,
+    is_open: bool,
+}
+
+impl<Message> std::fmt::Debug for DropDownList<Message> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DropDownList")
+            .field("options", &self.options)
+            .field("selected", &self.selected)
+            .field("is_open", &self.is_open)
+            .finish()
+    }
+}
+
+impl<Message> DropDownList<Message> {
+    /// Creates a new [`DropDownList`].
+    ///
+    /// It expects:
+    ///   * the labels of the available options
+    ///   * the index of the currently selected option
+    ///   * a function that will be called when an option is picked. It
+    ///   receives the index of the option and must produce a `Message`.
+    ///
+    /// [`DropDownList`]: struct.DropDownList.html
+    pub fn new<F>(options: &[&str], selected: usize, f: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        DropDownList {
+            options: options.iter().map(|option| String::from(*option)).collect(),
+            selected,
+            on_selected: Box::new(f),
+            is_open: false,
+        }
+    }
+
+    fn selected_label(&self) -> &str {
+        self.options
+            .get(self.selected)
+            .map(String::as_str)
+            .unwrap_or("")
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for DropDownList<Message>
+where
+    Renderer: self::Renderer + text::Renderer,
+    Message: 'static + std::fmt::Debug,
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        Row::<(), Renderer>::new()
+            .spacing(10)
+            .align_items(Align::Center)
+            .push(Text::new(self.selected_label()))
+            .push(Column::new().width(16).height(16))
+            .node(renderer)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                if layout.bounds().contains(cursor_position) {
+                    self.is_open = !self.is_open;
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let children: Vec<_> = layout.children().collect();
+
+        text::Renderer::draw(
+            renderer,
+            children[0].bounds(),
+            self.selected_label(),
+            20.0,
+            Color::WHITE,
+            HorizontalAlignment::Left,
+            VerticalAlignment::Top,
+        );
+
+        self::Renderer::draw(
+            renderer,
+            cursor_position,
+            layout.bounds(),
+            self.is_open,
+        )
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.options.len().hash(state);
+        self.selected.hash(state);
+
+        for option in &self.options {
+            option.hash(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<Element<'_, Message, Renderer>> {
+        if !self.is_open {
+            return None;
+        }
+
+        Some(Element::new(List {
+            options: &self.options,
+            selected: self.selected,
+            on_selected: &*self.on_selected,
+            open: &mut self.is_open,
+            anchor: layout.bounds(),
+        }))
+    }
+}
+
+/// The expanded list of options of a [`DropDownList`], drawn as an overlay
+/// on top of the rest of the user interface.
+///
+/// Since the overlay is laid out from the top-left of the screen rather
+/// than from the [`DropDownList`]'s own position, it carries the `anchor`
+/// (the collapsed button's bounds, as seen by [`DropDownList::overlay`])
+/// and pads itself out to it, so the list ends up directly below the
+/// button instead of in the screen's corner.
+///
+/// [`DropDownList`]: struct.DropDownList.html
+/// [`DropDownList::overlay`]: struct.DropDownList.html#method.overlay
+struct List<'a, Message> {
+    options: &'a [String],
+    selected: usize,
+    on_selected: &'a dyn Fn(usize) -> Message,
+    open: &'a mut bool,
+    anchor: Rectangle<f32>,
+}
+
+impl<'a, Message> std::fmt::Debug for List<'a, Message> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("List")
+            .field("options", &self.options)
+            .field("selected", &self.selected)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for List<'a, Message>
+where
+    Renderer: self::Renderer + text::Renderer,
+    Message: 'static + std::fmt::Debug,
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        let mut options = Column::<(), Renderer>::new()
+            // Push the list down past the anchor's own top offset, so it
+            // starts right where the collapsed button ends.
+            .push(Column::new().height(
+                (self.anchor.y + self.anchor.height) as u16,
+            ));
+
+        for option in self.options {
+            options = options.push(Text::new(option).height(24));
+        }
+
+        Row::<(), Renderer>::new()
+            // Push the list past the anchor's left offset, so it lines up
+            // with the collapsed button horizontally.
+            .push(Column::new().width(self.anchor.x as u16))
+            .push(options)
+            .node(renderer)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                for (i, child) in option_layouts(layout).into_iter().enumerate()
+                {
+                    if child.bounds().contains(cursor_position) {
+                        messages.push((self.on_selected)(i));
+                        *self.open = false;
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                // The click landed outside every option: close the list, but
+                // let the event fall through so the widget underneath can
+                // still react to it.
+                //
+                // This only actually closes the list if the runtime offers
+                // the event to the overlay even when the click lands outside
+                // of it.
+                *self.open = false;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let options = option_layouts(layout);
+
+        let bounds = match options.first() {
+            Some(first) => options.iter().skip(1).fold(
+                first.bounds(),
+                |bounds, option| {
+                    let option_bounds = option.bounds();
+
+                    Rectangle {
+                        x: bounds.x.min(option_bounds.x),
+                        y: bounds.y.min(option_bounds.y),
+                        width: bounds.width.max(option_bounds.width),
+                        height: bounds.height + option_bounds.height,
+                    }
+                },
+            ),
+            None => layout.bounds(),
+        };
+
+        self::Renderer::draw_list(
+            renderer,
+            bounds,
+            self.options,
+            self.selected,
+            cursor_position,
+        )
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.options.len().hash(state);
+
+        for option in self.options {
+            option.hash(state);
+        }
+
+        (self.anchor.x as u32).hash(state);
+        (self.anchor.y as u32).hash(state);
+        (self.anchor.height as u32).hash(state);
+    }
+}
+
+/// Returns the [`Layout`] of each option, skipping the spacers [`List::node`]
+/// pushes to anchor the list below and to the right of the collapsed
+/// [`DropDownList`] button.
+///
+/// [`Layout`]: ../../core/struct.Layout.html
+/// [`List::node`]: struct.List.html#method.node
+/// [`DropDownList`]: struct.DropDownList.html
+fn option_layouts<'a>(layout: Layout<'a>) -> Vec<Layout<'a>> {
+    let columns: Vec<_> = layout.children().collect();
+
+    match columns.get(1) {
+        Some(options) => options.children().skip(1).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// The renderer of a [`DropDownList`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`DropDownList`] in your user interface.
+///
+/// [`DropDownList`]: struct.DropDownList.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws the collapsed [`DropDownList`] button.
+    ///
+    /// It receives:
+    ///   * the current cursor position
+    ///   * the bounds of the [`DropDownList`]
+    ///   * whether the list of options is currently expanded or not
+    ///
+    /// [`DropDownList`]: struct.DropDownList.html
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        is_open: bool,
+    ) -> MouseCursor;
+
+    /// Draws the expanded list of options of a [`DropDownList`].
+    ///
+    /// It receives:
+    ///   * the bounds the list should be drawn in
+    ///   * the labels of the options
+    ///   * the index of the currently selected option
+    ///   * the current cursor position
+    ///
+    /// [`DropDownList`]: struct.DropDownList.html
+    fn draw_list(
+        &mut self,
+        bounds: Rectangle<f32>,
+        options: &[String],
+        selected: usize,
+        cursor_position: Point,
+    ) -> MouseCursor;
+}
+
+impl<'a, Message, Renderer> From<DropDownList<Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer,
+    Message: 'static + std::fmt::Debug,
+{
+    fn from(
+        dropdown: DropDownList<Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(dropdown)
+    }
+}
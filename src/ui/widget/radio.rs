@@ -2,9 +2,10 @@
 use crate::graphics::{
     Color, HorizontalAlignment, Point, Rectangle, VerticalAlignment,
 };
-use crate::input::{mouse, ButtonState};
+use crate::input::{keyboard, mouse, ButtonState};
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Layout, MouseCursor, Node, Widget,
+    event, Align, Clipboard, Element, Event, Hasher, Layout, MouseCursor,
+    Node, Widget,
 };
 use crate::ui::widget::{text, Column, Row, Text};
 
@@ -55,6 +56,9 @@ pub struct Radio<Message> {
     on_click: Message,
     label: String,
     label_color: Color,
+    style: Box<dyn StyleSheet>,
+    interaction: Interaction,
+    is_focused: bool,
 }
 
 impl<Message> std::fmt::Debug for Radio<Message>
@@ -67,6 +71,8 @@ where
             .field("on_click", &self.on_click)
             .field("label", &self.label)
             .field("label_color", &self.label_color)
+            .field("interaction", &self.interaction)
+            .field("is_focused", &self.is_focused)
             .finish()
     }
 }
@@ -92,6 +98,9 @@ impl<Message> Radio<Message> {
             on_click: f(value),
             label: String::from(label),
             label_color: Color::WHITE,
+            style: Default::default(),
+            interaction: Interaction::Normal,
+            is_focused: false,
         }
     }
 
@@ -103,6 +112,30 @@ impl<Message> Radio<Message> {
         self.label_color = color;
         self
     }
+
+    /// Sets the style of the [`Radio`] button.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub fn style(mut self, style: impl StyleSheet + 'static) -> Self {
+        self.style = Box::new(style);
+        self
+    }
+
+    /// Marks the [`Radio`] as focused, so it immediately responds to the
+    /// Space/Enter keys.
+    ///
+    /// A standalone [`Radio`] does not participate in any implicit tab
+    /// order, so unless it is part of a [`RadioGroup`] (which manages
+    /// focus for its members automatically), the embedder is responsible
+    /// for deciding which `Radio` should be focused and keeping that
+    /// decision in sync with the rest of its application state.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn focused(mut self, is_focused: bool) -> Self {
+        self.is_focused = is_focused;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Radio<Message>
@@ -119,24 +152,78 @@ where
             .node(renderer)
     }
 
+    fn focusable(&self) -> bool {
+        true
+    }
+
     fn on_event(
         &mut self,
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) {
+    ) -> event::Status {
+        let is_mouse_over = layout.bounds().contains(cursor_position);
+
         match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                self.interaction = if self.interaction == Interaction::Pressed
+                {
+                    Interaction::Pressed
+                } else if is_mouse_over {
+                    Interaction::Hovered
+                } else {
+                    Interaction::Normal
+                };
+            }
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state: ButtonState::Pressed,
             }) => {
-                if layout.bounds().contains(cursor_position) {
+                if is_mouse_over {
+                    self.interaction = Interaction::Pressed;
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Released,
+            }) => {
+                if self.interaction == Interaction::Pressed {
+                    self.interaction = if is_mouse_over {
+                        Interaction::Hovered
+                    } else {
+                        Interaction::Normal
+                    };
+
+                    if is_mouse_over {
+                        messages.push(self.on_click);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::Space,
+                state: ButtonState::Pressed,
+            })
+            | Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::Enter,
+                state: ButtonState::Pressed,
+            }) => {
+                if self.is_focused {
                     messages.push(self.on_click);
+
+                    return event::Status::Captured;
                 }
             }
             _ => {}
         }
+
+        event::Status::Ignored
     }
 
     fn draw(
@@ -150,12 +237,19 @@ where
         let mut text_bounds = children[1].bounds();
         text_bounds.y -= 2.0;
 
+        let style = match self.interaction {
+            Interaction::Normal => self.style.active(),
+            Interaction::Hovered | Interaction::Pressed => {
+                self.style.hovered()
+            }
+        };
+
         text::Renderer::draw(
             renderer,
             text_bounds,
             &self.label,
             20.0,
-            self.label_color,
+            style.text_color.unwrap_or(self.label_color),
             HorizontalAlignment::Left,
             VerticalAlignment::Top,
         );
@@ -166,6 +260,9 @@ where
             children[0].bounds(),
             layout.bounds(),
             self.is_selected,
+            style,
+            self.interaction == Interaction::Pressed,
+            self.is_focused,
         )
     }
 
@@ -189,17 +286,140 @@ pub trait Renderer {
     ///   * the bounds of the [`Radio`]
     ///   * the bounds of the label of the [`Radio`]
     ///   * whether the [`Radio`] is selected or not
+    ///   * the [`Style`] the [`Radio`] should be drawn with
+    ///   * whether the [`Radio`] is currently pressed, so the renderer can
+    ///   darken it while the button is held
+    ///   * whether the [`Radio`] currently holds keyboard focus, so the
+    ///   renderer can draw a focus ring around it
     ///
     /// [`Radio`]: struct.Radio.html
+    /// [`Style`]: struct.Style.html
     fn draw(
         &mut self,
         cursor_position: Point,
         bounds: Rectangle<f32>,
         label_bounds: Rectangle<f32>,
         is_selected: bool,
+        style: Style,
+        is_pressed: bool,
+        is_focused: bool,
     ) -> MouseCursor;
 }
 
+/// The interaction state of a [`Radio`] button.
+///
+/// [`Radio`]: struct.Radio.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Interaction {
+    /// The [`Radio`] is not being interacted with.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    Normal,
+
+    /// The cursor is over the [`Radio`].
+    ///
+    /// [`Radio`]: struct.Radio.html
+    Hovered,
+
+    /// The [`Radio`] is being pressed.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    Pressed,
+}
+
+/// The appearance of a [`Radio`] button.
+///
+/// [`Radio`]: struct.Radio.html
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The background [`Color`] of the [`Radio`] circle.
+    ///
+    /// [`Color`]: ../../../graphics/struct.Color.html
+    /// [`Radio`]: struct.Radio.html
+    pub background: Color,
+
+    /// The [`Color`] of the outline of the [`Radio`] circle.
+    ///
+    /// [`Color`]: ../../../graphics/struct.Color.html
+    /// [`Radio`]: struct.Radio.html
+    pub border_color: Color,
+
+    /// The width of the outline of the [`Radio`] circle.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    pub border_width: f32,
+
+    /// The [`Color`] of the dot that marks a selected [`Radio`].
+    ///
+    /// [`Color`]: ../../../graphics/struct.Color.html
+    /// [`Radio`]: struct.Radio.html
+    pub dot_color: Color,
+
+    /// The [`Color`] of the label of the [`Radio`].
+    ///
+    /// [`Color`]: ../../../graphics/struct.Color.html
+    /// [`Radio`]: struct.Radio.html
+    pub text_color: Option<Color>,
+}
+
+/// A set of rules that dictate the style of a [`Radio`] button.
+///
+/// [`Radio`]: struct.Radio.html
+pub trait StyleSheet {
+    /// Produces the default [`Style`] of a [`Radio`] button.
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`Radio`]: struct.Radio.html
+    fn active(&self) -> Style;
+
+    /// Produces the [`Style`] of a [`Radio`] button when it is being hovered.
+    ///
+    /// By default, it returns the same [`Style`] as [`active`].
+    ///
+    /// [`Style`]: struct.Style.html
+    /// [`active`]: #tymethod.active
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+}
+
+struct DefaultStyle;
+
+impl StyleSheet for DefaultStyle {
+    fn active(&self) -> Style {
+        Style {
+            background: Color::WHITE,
+            border_color: Color {
+                r: 0.6,
+                g: 0.6,
+                b: 0.6,
+                a: 1.0,
+            },
+            border_width: 1.0,
+            dot_color: Color {
+                r: 0.3,
+                g: 0.3,
+                b: 0.3,
+                a: 1.0,
+            },
+            text_color: None,
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            border_color: Color::BLACK,
+            ..self.active()
+        }
+    }
+}
+
+impl Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(DefaultStyle)
+    }
+}
+
 impl<'a, Message, Renderer> From<Radio<Message>>
     for Element<'a, Message, Renderer>
 where
@@ -210,3 +430,243 @@ where
         Element::new(checkbox)
     }
 }
+
+/// A group of [`Radio`] buttons that share a single selection.
+///
+/// Unlike placing [`Radio`] buttons side by side, a [`RadioGroup`] keeps
+/// track of which member currently holds keyboard focus and lets the user
+/// move that focus with the Up/Down (or Left/Right) arrow keys, emitting
+/// the corresponding `on_click` message as the focus moves.
+///
+/// A [`RadioGroup`] only reacts to the arrow keys once it actually holds
+/// focus: it starts out unfocused and claims focus the moment one of its
+/// [`Radio`]s is clicked (use [`RadioGroup::focused`] to focus it up
+/// front instead), so that multiple groups in the same user interface
+/// don't all fight over every arrow-key press.
+///
+/// [`Radio`]: struct.Radio.html
+/// [`RadioGroup`]: struct.RadioGroup.html
+/// [`RadioGroup::focused`]: struct.RadioGroup.html#method.focused
+pub struct RadioGroup<Message> {
+    radios: Vec<Radio<Message>>,
+    focused: usize,
+    has_focus: bool,
+}
+
+impl<Message> std::fmt::Debug for RadioGroup<Message>
+where
+    Message: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RadioGroup")
+            .field("radios", &self.radios)
+            .field("focused", &self.focused)
+            .field("has_focus", &self.has_focus)
+            .finish()
+    }
+}
+
+impl<Message> RadioGroup<Message> {
+    /// Creates a new [`RadioGroup`] from a list of `(value, label)` options.
+    ///
+    /// It expects:
+    ///   * the available options, as `(value, label)` pairs
+    ///   * the current selected value
+    ///   * a function that will be called when a [`Radio`] in the group is
+    ///   selected. It receives the value of the radio and must produce a
+    ///   `Message`.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn new<F, V>(options: &[(V, &str)], selected: Option<V>, f: F) -> Self
+    where
+        V: Eq + Copy,
+        F: 'static + Fn(V) -> Message + Clone,
+    {
+        let focused = options
+            .iter()
+            .position(|(value, _)| Some(*value) == selected)
+            .unwrap_or(0);
+
+        let mut radios: Vec<Radio<Message>> = options
+            .iter()
+            .map(|(value, label)| {
+                Radio::new(*value, label, selected, f.clone())
+            })
+            .collect();
+
+        if let Some(radio) = radios.get_mut(focused) {
+            radio.is_focused = true;
+        }
+
+        RadioGroup {
+            radios,
+            focused,
+            has_focus: false,
+        }
+    }
+
+    /// Marks the [`RadioGroup`] as currently holding keyboard focus, so it
+    /// responds to the Up/Down/Left/Right keys right away instead of
+    /// waiting for a click on one of its [`Radio`]s.
+    ///
+    /// Only one focused widget should exist at a time; the embedder is
+    /// responsible for keeping that invariant, the same way it already
+    /// does for the `selected` value passed to [`RadioGroup::new`].
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    /// [`Radio`]: struct.Radio.html
+    /// [`RadioGroup::new`]: struct.RadioGroup.html#method.new
+    pub fn focused(mut self, has_focus: bool) -> Self {
+        self.has_focus = has_focus;
+        self
+    }
+
+    fn move_focus(&mut self, delta: isize, messages: &mut Vec<Message>)
+    where
+        Message: Copy,
+    {
+        if self.radios.is_empty() {
+            return;
+        }
+
+        if let Some(radio) = self.radios.get_mut(self.focused) {
+            radio.is_focused = false;
+        }
+
+        let len = self.radios.len() as isize;
+        self.focused = (self.focused as isize + delta).rem_euclid(len) as usize;
+
+        let radio = &mut self.radios[self.focused];
+        radio.is_focused = true;
+
+        messages.push(radio.on_click);
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for RadioGroup<Message>
+where
+    Renderer: self::Renderer + text::Renderer,
+    Message: Copy + std::fmt::Debug,
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        let mut column = Column::<(), Renderer>::new().spacing(10);
+
+        for radio in &self.radios {
+            column = column.push(
+                Row::<(), Renderer>::new()
+                    .spacing(15)
+                    .align_items(Align::Center)
+                    .push(Column::new().width(28).height(28))
+                    .push(Text::new(&radio.label)),
+            );
+        }
+
+        column.node(renderer)
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        if self.has_focus {
+            if let Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+            }) = &event
+            {
+                match key_code {
+                    keyboard::KeyCode::Down | keyboard::KeyCode::Right => {
+                        self.move_focus(1, messages);
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Left => {
+                        self.move_focus(-1, messages);
+
+                        return event::Status::Captured;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut status = event::Status::Ignored;
+
+        for (radio, child) in self.radios.iter_mut().zip(layout.children()) {
+            status = status.merge(radio.on_event(
+                event,
+                child,
+                cursor_position,
+                renderer,
+                clipboard,
+                messages,
+            ));
+
+            if status == event::Status::Captured {
+                break;
+            }
+        }
+
+        // A click on one of our radios means the group is now the thing
+        // the user is interacting with, so it should start claiming the
+        // Up/Down/Left/Right keys instead of whichever `RadioGroup`
+        // happened to grab them first.
+        if status == event::Status::Captured {
+            self.has_focus = true;
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let cursors: Vec<(Rectangle<f32>, MouseCursor)> = self
+            .radios
+            .iter()
+            .zip(layout.children())
+            .map(|(radio, child)| {
+                let bounds = child.bounds();
+
+                (bounds, radio.draw(renderer, child, cursor_position))
+            })
+            .collect();
+
+        cursors
+            .iter()
+            .find(|(bounds, _)| bounds.contains(cursor_position))
+            .or_else(|| cursors.first())
+            .map(|(_, cursor)| *cursor)
+            .unwrap_or(MouseCursor::OutOfBounds)
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        for radio in &self.radios {
+            radio.hash(state);
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<RadioGroup<Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer,
+    Message: 'static + Copy + std::fmt::Debug,
+{
+    fn from(group: RadioGroup<Message>) -> Element<'a, Message, Renderer> {
+        Element::new(group)
+    }
+}
@@ -0,0 +1,201 @@
+//! Lay out widgets horizontally.
+use crate::graphics::{Point, Rectangle, Size};
+use crate::ui::core::{
+    event, Align, Clipboard, Element, Event, Hasher, Layout, MouseCursor,
+    Node, Widget,
+};
+
+use std::hash::Hash;
+
+/// A container that distributes its children horizontally.
+pub struct Row<'a, Message, Renderer> {
+    spacing: f32,
+    align_items: Align,
+    width: Option<f32>,
+    height: Option<f32>,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for Row<'a, Message, Renderer> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Row")
+            .field("children", &self.children.len())
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
+    /// Creates an empty [`Row`].
+    ///
+    /// [`Row`]: struct.Row.html
+    pub fn new() -> Self {
+        Row {
+            spacing: 0.0,
+            align_items: Align::Start,
+            width: None,
+            height: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the horizontal spacing _between_ the children of the [`Row`].
+    ///
+    /// [`Row`]: struct.Row.html
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = f32::from(spacing);
+        self
+    }
+
+    /// Sets the vertical alignment of the children of the [`Row`].
+    ///
+    /// [`Row`]: struct.Row.html
+    pub fn align_items(mut self, align_items: Align) -> Self {
+        self.align_items = align_items;
+        self
+    }
+
+    /// Sets a fixed width for the [`Row`], instead of its intrinsic one.
+    ///
+    /// [`Row`]: struct.Row.html
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(f32::from(width));
+        self
+    }
+
+    /// Sets a fixed height for the [`Row`], instead of its intrinsic one.
+    ///
+    /// [`Row`]: struct.Row.html
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = Some(f32::from(height));
+        self
+    }
+
+    /// Adds a child [`Element`] to the [`Row`].
+    ///
+    /// [`Element`]: ../../core/struct.Element.html
+    /// [`Row`]: struct.Row.html
+    pub fn push(
+        mut self,
+        child: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Row<'a, Message, Renderer>
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        let children: Vec<Node> =
+            self.children.iter().map(|child| child.node(renderer)).collect();
+
+        let spacing = self.spacing * children.len().saturating_sub(1) as f32;
+
+        let width = self.width.unwrap_or_else(|| {
+            children.iter().map(Node::size).map(|size| size.width).sum::<f32>()
+                + spacing
+        });
+
+        let height = self.height.unwrap_or_else(|| {
+            children
+                .iter()
+                .map(Node::size)
+                .map(|size| size.height)
+                .fold(0.0, f32::max)
+        });
+
+        Node::with_children(Size::new(width, height), children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let mut status = event::Status::Ignored;
+
+        for (child, child_layout) in
+            self.children.iter_mut().zip(layout.children())
+        {
+            status = status.merge(child.on_event(
+                event,
+                child_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                messages,
+            ));
+
+            // Once a child captures an event, it must not reach the
+            // remaining siblings (e.g. a `Radio` capturing a click that
+            // would otherwise also be seen by the `Radio` next to it).
+            if status == event::Status::Captured {
+                break;
+            }
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let cursors: Vec<(Rectangle<f32>, MouseCursor)> = self
+            .children
+            .iter()
+            .zip(layout.children())
+            .map(|(child, child_layout)| {
+                let bounds = child_layout.bounds();
+
+                (bounds, child.draw(renderer, child_layout, cursor_position))
+            })
+            .collect();
+
+        cursors
+            .iter()
+            .find(|(bounds, _)| bounds.contains(cursor_position))
+            .or_else(|| cursors.first())
+            .map(|(_, cursor)| *cursor)
+            .unwrap_or(MouseCursor::OutOfBounds)
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.align_items.hash(state);
+        (self.spacing as u32).hash(state);
+        self.width.map(|width| width as u32).hash(state);
+        self.height.map(|height| height as u32).hash(state);
+
+        for child in &self.children {
+            child.hash(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<Element<'_, Message, Renderer>> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .find_map(|(child, child_layout)| child.overlay(child_layout))
+    }
+}
+
+impl<'a, Message, Renderer> From<Row<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a,
+    Message: 'a,
+{
+    fn from(row: Row<'a, Message, Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(row)
+    }
+}